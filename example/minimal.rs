@@ -3,20 +3,17 @@ use bevy_file_asset::FileAssetPlugin;
 
 fn main() {
     App::new()
-        .add_plugins((
-            FileAssetPlugin,
-            DefaultPlugins,
-        ))
+        .add_plugins((FileAssetPlugin::default(), DefaultPlugins))
         .add_systems(Startup, setup)
         .run();
 }
 
 fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
-    commands.spawn(Camera2d::default());
+    commands.spawn(Camera2dBundle::default());
 
-    let image = asset_server.load("file://docs/image.png");
-    commands.spawn(Sprite {
-        image,
+    let texture = asset_server.load("file://docs/image.png");
+    commands.spawn(SpriteBundle {
+        texture,
         ..Default::default()
     });
 }
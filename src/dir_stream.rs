@@ -0,0 +1,93 @@
+use futures::stream::Stream;
+use std::{
+    fs::{self, ReadDir},
+    io,
+    path::{Path, PathBuf},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A [`Stream`] of paths that lazily walks a directory tree, descending into subdirectories
+/// as it is polled rather than buffering the entire tree up front.
+///
+/// Each directory is only opened with `fs::read_dir` once the stream reaches it, so deep or
+/// wide trees don't pay for a large up-front allocation.
+pub(crate) struct RecursiveDirStream {
+    /// One `ReadDir` iterator per directory currently being descended into, with the
+    /// innermost (most recently entered) directory last.
+    stack: Vec<ReadDir>,
+}
+
+impl RecursiveDirStream {
+    /// Starts a recursive walk rooted at `path`.
+    pub(crate) fn new(path: &Path) -> io::Result<Self> {
+        Ok(Self {
+            stack: vec![fs::read_dir(path)?],
+        })
+    }
+}
+
+impl Stream for RecursiveDirStream {
+    type Item = PathBuf;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<PathBuf>> {
+        loop {
+            let Some(read_dir) = self.stack.last_mut() else {
+                return Poll::Ready(None);
+            };
+
+            match read_dir.next() {
+                Some(Ok(entry)) => {
+                    let path = entry.path();
+                    // Only descend into real directories, not symlinks to directories: `is_dir`
+                    // follows symlinks, and a symlink cycle (e.g. a directory linking back to
+                    // one of its own ancestors) would otherwise make this stream recurse
+                    // forever. A symlink is yielded as a leaf path instead.
+                    if entry.file_type().map(|ty| ty.is_dir()).unwrap_or(false) {
+                        // Descend into the subdirectory instead of yielding it directly; only
+                        // file paths are reported, since Bevy's folder loading recurses into any
+                        // directory path it is handed and would otherwise visit each file twice.
+                        if let Ok(nested) = fs::read_dir(&path) {
+                            self.stack.push(nested);
+                        }
+                        continue;
+                    }
+                    return Poll::Ready(Some(path));
+                }
+                Some(Err(_)) => continue,
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_recursive_dir_stream_visits_nested_files() {
+        let root = tempdir().unwrap();
+        fs::create_dir_all(root.path().join("a/b")).unwrap();
+        fs::write(root.path().join("top.txt"), b"").unwrap();
+        fs::write(root.path().join("a/mid.txt"), b"").unwrap();
+        fs::write(root.path().join("a/b/deep.txt"), b"").unwrap();
+
+        let stream = RecursiveDirStream::new(root.path()).unwrap();
+        let mut paths: Vec<PathBuf> = stream.collect().await;
+        paths.sort();
+
+        let mut expected = vec![
+            root.path().join("top.txt"),
+            root.path().join("a/mid.txt"),
+            root.path().join("a/b/deep.txt"),
+        ];
+        expected.sort();
+
+        assert_eq!(paths, expected);
+    }
+}
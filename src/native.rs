@@ -0,0 +1,178 @@
+use crate::{
+    dir_stream::RecursiveDirStream, make_meta_path, sandbox::Sandbox, watcher::WatchedPathsHandle,
+};
+use bevy::{
+    asset::io::{AssetReader, AssetReaderError, PathStream, Reader, VecReader},
+    tasks::IoTaskPool,
+    utils::ConditionalSendFuture,
+};
+use futures::stream::Stream;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    pin::Pin,
+};
+
+/// Submits a blocking operation to Bevy's [`IoTaskPool`] and awaits its result.
+///
+/// This reuses the engine's existing I/O worker threads instead of spawning and tearing down
+/// a dedicated OS thread for every file read.
+pub(crate) async fn spawn_blocking<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    IoTaskPool::get().spawn(async move { f() }).await
+}
+
+/// A reader that loads files directly from arbitrary paths.
+#[derive(Default)]
+pub struct FileAssetReader {
+    pub(crate) watched_paths: WatchedPathsHandle,
+    pub(crate) sandbox: Sandbox,
+}
+
+impl FileAssetReader {
+    /// Asynchronously reads the file at the given path using blocking I/O on Bevy's
+    /// [`IoTaskPool`].
+    async fn file_get<'a>(path: PathBuf) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        let path_for_error = path.clone();
+        let result = spawn_blocking(move || fs::read(&path)).await;
+
+        match result {
+            Ok(bytes) => Ok(Box::new(VecReader::new(bytes))),
+            Err(err) => {
+                if err.kind() == std::io::ErrorKind::NotFound {
+                    Err(AssetReaderError::NotFound(path_for_error))
+                } else {
+                    Err(AssetReaderError::Io(err.into()))
+                }
+            }
+        }
+    }
+}
+
+impl AssetReader for FileAssetReader {
+    #[allow(refining_impl_trait)]
+    fn read<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> impl ConditionalSendFuture<Output = Result<Box<Reader<'a>>, AssetReaderError>> + 'a
+    {
+        let path_buf = path.to_path_buf();
+        async move {
+            let checked_path = self
+                .sandbox
+                .check(&path_buf)
+                .map_err(AssetReaderError::NotFound)?;
+            // Attempt to load the file directly from the given path.
+            if checked_path.exists() {
+                let reader = Self::file_get(checked_path).await?;
+                // Watch the path as given, not the canonicalized one, so events reported by
+                // `notify` match the path Bevy is expecting to hear about again.
+                self.watched_paths.watch(&path_buf);
+                Ok(reader)
+            } else {
+                self.watched_paths.unwatch(&path_buf);
+                // If the file isn’t found, return a NotFound error to signal fallback.
+                Err(AssetReaderError::NotFound(path_buf))
+            }
+        }
+    }
+
+    async fn read_meta<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        // Derive the meta path from the source path as given, then sandbox-check the meta path
+        // itself rather than reusing the already-checked source path: the two can diverge (e.g.
+        // the meta sidecar falling outside an allowed root even though the source is inside it).
+        let Some(meta_path) = make_meta_path(path) else {
+            return Err(AssetReaderError::NotFound(
+                "source path has no extension".into(),
+            ));
+        };
+        let checked_meta_path = self
+            .sandbox
+            .check(&meta_path)
+            .map_err(AssetReaderError::NotFound)?;
+        if checked_meta_path.exists() {
+            let reader = Self::file_get(checked_meta_path).await?;
+            self.watched_paths.watch(&meta_path);
+            Ok(reader)
+        } else {
+            self.watched_paths.unwatch(&meta_path);
+            Err(AssetReaderError::NotFound(meta_path))
+        }
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        let path = self
+            .sandbox
+            .check(path)
+            .map_err(AssetReaderError::NotFound)?;
+        Ok(path.is_dir())
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let path = self
+            .sandbox
+            .check(path)
+            .map_err(AssetReaderError::NotFound)?;
+        if path.is_dir() {
+            let stream =
+                RecursiveDirStream::new(&path).map_err(|e| AssetReaderError::Io(e.into()))?;
+            // Box and pin the stream to satisfy the PathStream type.
+            let boxed_stream: Pin<Box<dyn Stream<Item = PathBuf> + Send>> = Box::pin(stream);
+            Ok(Box::new(boxed_stream))
+        } else {
+            Err(AssetReaderError::NotFound(path.to_path_buf()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::tasks::{IoTaskPool, TaskPoolBuilder};
+    use futures::AsyncReadExt;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    // `spawn_blocking` submits work to Bevy's `IoTaskPool`, which panics if nothing has
+    // initialized it yet; outside of a running `App` that's normally done by `TaskPoolPlugin`.
+    fn init_io_task_pool() {
+        IoTaskPool::get_or_init(|| TaskPoolBuilder::default().build());
+    }
+
+    #[tokio::test]
+    async fn test_file_asset_reader() {
+        init_io_task_pool();
+        // Create a temporary file with known contents.
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "Hello, Bevy!").unwrap();
+        let path = file.path().to_path_buf();
+
+        let asset_reader = FileAssetReader::default();
+        let mut vec_reader = asset_reader.read(&path).await.unwrap();
+        let mut content = Vec::new();
+        // Await the future returned by read_to_end
+        vec_reader
+            .read_to_end(&mut content)
+            .await
+            .expect("Failed to read content");
+        assert!(String::from_utf8_lossy(&content).contains("Hello, Bevy!"));
+    }
+
+    #[tokio::test]
+    async fn test_file_asset_reader_not_found() {
+        init_io_task_pool();
+        let path = PathBuf::from("non_existent_file.txt");
+        let reader = FileAssetReader::default();
+        let result = reader.read(&path).await;
+        assert!(matches!(result, Err(AssetReaderError::NotFound(_))));
+    }
+}
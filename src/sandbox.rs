@@ -0,0 +1,136 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// Path-access policy shared between [`FileAssetPlugin`](crate::FileAssetPlugin), the reader,
+/// and the writer.
+///
+/// By default every path is permitted, matching the plugin's historical behavior. Once one or
+/// more roots are configured, a requested path is only served if it canonicalizes into one of
+/// them, which also rejects `..` traversal that would otherwise escape the allowed roots. If
+/// `path` doesn't exist yet, `check` walks up to the nearest ancestor that does, canonicalizes
+/// that, and rejoins the missing components, so the writer can validate a path it's about to
+/// create via `create_dir_all` even when none of its parent directories exist yet.
+///
+/// Returns the rejected path as the error so each caller can map it to its own error type
+/// (`AssetReaderError::NotFound` for reads, `AssetWriterError::Io` for writes).
+#[derive(Clone)]
+pub(crate) struct Sandbox {
+    roots: Option<Arc<[PathBuf]>>,
+    allow_absolute: bool,
+}
+
+impl Default for Sandbox {
+    fn default() -> Self {
+        Self {
+            roots: None,
+            allow_absolute: true,
+        }
+    }
+}
+
+impl Sandbox {
+    pub(crate) fn new(roots: Option<Vec<PathBuf>>, allow_absolute: bool) -> Self {
+        Self {
+            roots: roots.map(Arc::from),
+            allow_absolute,
+        }
+    }
+
+    /// Checks `path` against the configured roots and absolute-path policy, returning the
+    /// path to actually operate on if it's allowed, or `path` back as an error if it isn't.
+    pub(crate) fn check(&self, path: &Path) -> Result<PathBuf, PathBuf> {
+        if !self.allow_absolute && path.is_absolute() {
+            return Err(path.to_path_buf());
+        }
+
+        let Some(roots) = &self.roots else {
+            return Ok(path.to_path_buf());
+        };
+
+        // Prefer canonicalizing the full path: this resolves any symlink at `path` itself, so
+        // a symlink planted inside an allowed root can't be used to escape it. Only fall back
+        // to walking up to the nearest existing ancestor if `path` (or one of its parents)
+        // doesn't exist yet, which the writer relies on to validate a file it's about to
+        // create inside directories that `create_dir_all` will create on the fly.
+        let canonical = match fs::canonicalize(path) {
+            Ok(canonical) => canonical,
+            Err(_) => {
+                let mut missing = Vec::new();
+                let mut ancestor = path;
+                let canonical_ancestor = loop {
+                    let parent = match ancestor.parent() {
+                        // A relative path's first component has an empty parent: the working
+                        // directory.
+                        Some(parent) if parent.as_os_str().is_empty() => Path::new("."),
+                        Some(parent) => parent,
+                        None => return Err(path.to_path_buf()),
+                    };
+                    let name = ancestor.file_name().ok_or_else(|| path.to_path_buf())?;
+                    match fs::canonicalize(parent) {
+                        Ok(canonical_parent) => {
+                            missing.push(name);
+                            break canonical_parent;
+                        }
+                        Err(_) => {
+                            missing.push(name);
+                            ancestor = parent;
+                        }
+                    }
+                };
+
+                missing
+                    .into_iter()
+                    .rev()
+                    .fold(canonical_ancestor, |acc, part| acc.join(part))
+            }
+        };
+
+        let within_roots = roots.iter().any(|root| {
+            fs::canonicalize(root)
+                .map(|root| canonical.starts_with(root))
+                .unwrap_or(false)
+        });
+
+        if within_roots {
+            Ok(canonical)
+        } else {
+            Err(path.to_path_buf())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_check_rejects_path_outside_roots() {
+        let root = tempdir().unwrap();
+        let outside = tempdir().unwrap();
+        let sandbox = Sandbox::new(Some(vec![root.path().to_path_buf()]), true);
+
+        assert!(sandbox.check(&outside.path().join("asset.png")).is_err());
+    }
+
+    #[test]
+    fn test_check_rejects_traversal_out_of_root() {
+        let root = tempdir().unwrap();
+        let sandbox = Sandbox::new(Some(vec![root.path().to_path_buf()]), true);
+
+        assert!(sandbox.check(&root.path().join("../escaped.png")).is_err());
+    }
+
+    #[test]
+    fn test_check_allows_not_yet_created_nested_directory() {
+        let root = tempdir().unwrap();
+        let sandbox = Sandbox::new(Some(vec![root.path().to_path_buf()]), true);
+
+        let nested = root.path().join("a/b/c/asset.png");
+        let checked = sandbox.check(&nested).unwrap();
+        assert!(checked.ends_with("a/b/c/asset.png"));
+    }
+}
@@ -0,0 +1,158 @@
+use crate::make_meta_path;
+use bevy::asset::io::{AssetReader, AssetReaderError, PathStream, Reader, VecReader};
+use futures::stream;
+use js_sys::Uint8Array;
+use std::{
+    io,
+    path::{Component, Path, PathBuf},
+    pin::Pin,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{DomException, FileSystemDirectoryHandle, FileSystemFileHandle};
+
+/// A reader that loads files from the browser's Origin Private File System (OPFS).
+///
+/// This mirrors the native `FileAssetReader`, but `std::fs` and OS threads don't exist on
+/// `wasm32`, so each `file://` path is resolved as a walk through OPFS directory handles and
+/// read via `wasm-bindgen-futures` instead of a blocking thread.
+#[derive(Default)]
+pub struct FileAssetReader;
+
+impl FileAssetReader {
+    /// Walks `path` one component at a time through nested OPFS directory handles, returning
+    /// the handle of the containing directory together with the final file name.
+    async fn resolve_parent(
+        path: &Path,
+    ) -> Result<(FileSystemDirectoryHandle, String), AssetReaderError> {
+        let mut components: Vec<String> = path
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(part) => Some(part.to_string_lossy().into_owned()),
+                _ => None,
+            })
+            .collect();
+
+        let file_name = components
+            .pop()
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_path_buf()))?;
+
+        let mut dir = opfs_root(path).await?;
+        for part in components {
+            dir = get_directory_handle(path, &dir, &part)
+                .await?
+                .ok_or_else(|| AssetReaderError::NotFound(path.to_path_buf()))?;
+        }
+
+        Ok((dir, file_name))
+    }
+
+    /// Reads the bytes of the file at `path` out of OPFS.
+    async fn file_get<'a>(path: PathBuf) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        let (dir, file_name) = Self::resolve_parent(&path).await?;
+
+        let file_handle = get_file_handle(&path, &dir, &file_name)
+            .await?
+            .ok_or_else(|| AssetReaderError::NotFound(path.clone()))?;
+
+        let file = JsFuture::from(file_handle.get_file())
+            .await
+            .map_err(|err| js_error(&path, err))?
+            .unchecked_into::<web_sys::File>();
+
+        let buffer = JsFuture::from(file.array_buffer())
+            .await
+            .map_err(|err| js_error(&path, err))?;
+
+        Ok(Box::new(VecReader::new(Uint8Array::new(&buffer).to_vec())))
+    }
+}
+
+impl AssetReader for FileAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        Self::file_get(path.to_path_buf()).await
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        let meta_path = make_meta_path(path)
+            .ok_or_else(|| AssetReaderError::NotFound("source path has no extension".into()))?;
+        Self::file_get(meta_path).await
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        let (dir, name) = Self::resolve_parent(path).await?;
+        Ok(get_directory_handle(path, &dir, &name).await?.is_some())
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        // OPFS doesn't offer a convenient way to enumerate entries without an async iterator
+        // protocol that `web-sys` doesn't yet expose ergonomically, so folder-based asset
+        // loading is unsupported on `wasm32` for now rather than guessing at entries.
+        let _ = path;
+        let stream: Pin<Box<dyn futures::stream::Stream<Item = PathBuf> + Send>> =
+            Box::pin(stream::iter(Vec::new()));
+        Ok(Box::new(stream))
+    }
+}
+
+/// Opens the root of the Origin Private File System.
+async fn opfs_root(path: &Path) -> Result<FileSystemDirectoryHandle, AssetReaderError> {
+    let storage = web_sys::window()
+        .ok_or_else(|| AssetReaderError::NotFound(path.to_path_buf()))?
+        .navigator()
+        .storage();
+
+    JsFuture::from(storage.get_directory())
+        .await
+        .map(|handle| handle.unchecked_into())
+        .map_err(|err| js_error(path, err))
+}
+
+/// Looks up a child directory handle, returning `Ok(None)` if it doesn't exist and `Err` for
+/// any other failure (permissions, quota, ...).
+async fn get_directory_handle(
+    path: &Path,
+    dir: &FileSystemDirectoryHandle,
+    name: &str,
+) -> Result<Option<FileSystemDirectoryHandle>, AssetReaderError> {
+    match JsFuture::from(dir.get_directory_handle(name)).await {
+        Ok(handle) => Ok(Some(handle.unchecked_into())),
+        Err(err) => match js_error(path, err) {
+            AssetReaderError::NotFound(_) => Ok(None),
+            other => Err(other),
+        },
+    }
+}
+
+/// Looks up a child file handle, returning `Ok(None)` if it doesn't exist and `Err` for any
+/// other failure (permissions, quota, ...).
+async fn get_file_handle(
+    path: &Path,
+    dir: &FileSystemDirectoryHandle,
+    name: &str,
+) -> Result<Option<FileSystemFileHandle>, AssetReaderError> {
+    match JsFuture::from(dir.get_file_handle(name)).await {
+        Ok(handle) => Ok(Some(handle.unchecked_into())),
+        Err(err) => match js_error(path, err) {
+            AssetReaderError::NotFound(_) => Ok(None),
+            other => Err(other),
+        },
+    }
+}
+
+/// Maps a failed OPFS operation to an `AssetReaderError`: a `NotFoundError` `DOMException`
+/// becomes [`AssetReaderError::NotFound`], anything else becomes [`AssetReaderError::Io`].
+fn js_error(path: &Path, err: JsValue) -> AssetReaderError {
+    let is_not_found = err
+        .dyn_ref::<DomException>()
+        .is_some_and(|exception| exception.name() == "NotFoundError");
+
+    if is_not_found {
+        AssetReaderError::NotFound(path.to_path_buf())
+    } else {
+        AssetReaderError::Io(io::Error::other(format!("{err:?}")).into())
+    }
+}
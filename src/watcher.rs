@@ -0,0 +1,190 @@
+use bevy::asset::io::{AssetSourceEvent, AssetWatcher};
+use crossbeam_channel::Sender;
+use notify_debouncer_full::{
+    new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode, Watcher},
+    DebounceEventResult, Debouncer, FileIdMap,
+};
+use std::{
+    collections::HashSet,
+    path::Path,
+    path::PathBuf,
+    sync::{Arc, Mutex, OnceLock},
+    time::Duration,
+};
+
+/// How long to wait after the last filesystem event on a path before reporting it, coalescing
+/// the bursts of writes that editors and build tools tend to produce.
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(300);
+
+type FileDebouncer = Debouncer<RecommendedWatcher, FileIdMap>;
+
+/// Tracks the set of paths the watcher is currently watching, since `file://` assets
+/// are scattered across arbitrary locations rather than living under one root folder.
+struct WatchedPaths {
+    debouncer: Mutex<FileDebouncer>,
+    watched: Mutex<HashSet<PathBuf>>,
+}
+
+impl WatchedPaths {
+    fn watch(&self, path: &Path) {
+        let mut watched = self.watched.lock().unwrap();
+        if watched.insert(path.to_path_buf()) {
+            let mut debouncer = self.debouncer.lock().unwrap();
+            debouncer.cache().add_root(path, RecursiveMode::NonRecursive);
+            let _ = debouncer
+                .watcher()
+                .watch(path, RecursiveMode::NonRecursive);
+        }
+    }
+
+    fn unwatch(&self, path: &Path) {
+        let mut watched = self.watched.lock().unwrap();
+        if watched.remove(path) {
+            let mut debouncer = self.debouncer.lock().unwrap();
+            let _ = debouncer.watcher().unwatch(path);
+            debouncer.cache().remove_root(path);
+        }
+    }
+}
+
+/// A shared handle that [`FileAssetReader`](crate::FileAssetReader) uses to register the
+/// individual paths it opens with the [`FileAssetWatcher`] running alongside it.
+///
+/// The watcher itself is only constructed once Bevy hands us its event sender, so the reader
+/// is built against this empty handle first and the handle is filled in once the watcher comes
+/// online; registering a path before then is simply a no-op.
+#[derive(Clone, Default)]
+pub(crate) struct WatchedPathsHandle(Arc<OnceLock<Arc<WatchedPaths>>>);
+
+impl WatchedPathsHandle {
+    pub(crate) fn watch(&self, path: &Path) {
+        if let Some(paths) = self.0.get() {
+            paths.watch(path);
+        }
+    }
+
+    pub(crate) fn unwatch(&self, path: &Path) {
+        if let Some(paths) = self.0.get() {
+            paths.unwatch(path);
+        }
+    }
+}
+
+/// A watcher that hot-reloads `file://` assets by dynamically tracking the paths (absolute or
+/// relative, as requested) opened through [`FileAssetReader`](crate::FileAssetReader).
+///
+/// Unlike most asset sources, `file://` has no single root directory to watch recursively, so
+/// paths are added and removed from the underlying [`notify`] watcher as the reader opens them.
+pub struct FileAssetWatcher {
+    // Kept alive only to hold the debouncer and its watch set open for as long as the asset
+    // source itself is alive.
+    #[allow(dead_code)]
+    paths: Arc<WatchedPaths>,
+}
+
+impl FileAssetWatcher {
+    /// Builds a watcher that forwards debounced filesystem events to `sender`, and fills in
+    /// `handle` so the paired [`FileAssetReader`](crate::FileAssetReader) can start registering paths with it.
+    pub(crate) fn new(
+        sender: Sender<AssetSourceEvent>,
+        handle: WatchedPathsHandle,
+    ) -> Option<Self> {
+        let debouncer = new_debouncer(DEBOUNCE_DELAY, None, move |result: DebounceEventResult| {
+            let Ok(events) = result else {
+                return;
+            };
+            for event in events {
+                for path in &event.paths {
+                    let _ = sender.send(AssetSourceEvent::ModifiedAsset(path.clone()));
+                }
+            }
+        })
+        .ok()?;
+
+        let paths = Arc::new(WatchedPaths {
+            debouncer: Mutex::new(debouncer),
+            watched: Mutex::new(HashSet::new()),
+        });
+        let _ = handle.0.set(paths.clone());
+
+        Some(Self { paths })
+    }
+}
+
+impl AssetWatcher for FileAssetWatcher {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::native::FileAssetReader;
+    use bevy::{
+        asset::io::AssetReader,
+        tasks::{IoTaskPool, TaskPoolBuilder},
+    };
+    use std::{fs, io::Write, time::Duration};
+    use tempfile::NamedTempFile;
+
+    fn init_io_task_pool() {
+        IoTaskPool::get_or_init(|| TaskPoolBuilder::default().build());
+    }
+
+    #[tokio::test]
+    async fn test_watch_reports_modified_asset_event() {
+        init_io_task_pool();
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let handle = WatchedPathsHandle::default();
+        let _watcher = FileAssetWatcher::new(sender, handle.clone()).unwrap();
+
+        let mut file = NamedTempFile::new().unwrap();
+        let path = file.path().to_path_buf();
+        let reader = FileAssetReader {
+            watched_paths: handle,
+            sandbox: Default::default(),
+        };
+
+        // Registers the path with the watcher as a side effect of reading it.
+        reader.read(&path).await.unwrap();
+
+        writeln!(file, "updated").unwrap();
+        file.flush().unwrap();
+
+        let event = receiver
+            .recv_timeout(Duration::from_secs(2))
+            .expect("expected a debounced event after the write");
+        assert!(matches!(
+            event,
+            AssetSourceEvent::ModifiedAsset(event_path) if event_path == path
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unwatch_on_missing_stops_reporting_events() {
+        init_io_task_pool();
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let handle = WatchedPathsHandle::default();
+        let _watcher = FileAssetWatcher::new(sender, handle.clone()).unwrap();
+
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_path_buf();
+        let reader = FileAssetReader {
+            watched_paths: handle,
+            sandbox: Default::default(),
+        };
+
+        reader.read(&path).await.unwrap();
+        file.close().unwrap();
+
+        // Reading the now-missing path reports NotFound and unwatches it.
+        assert!(reader.read(&path).await.is_err());
+
+        // Drain any debounced events the deletion itself produced before it was unwatched.
+        while receiver.recv_timeout(Duration::from_millis(500)).is_ok() {}
+
+        fs::write(&path, b"recreated").unwrap();
+        assert!(
+            receiver.recv_timeout(Duration::from_secs(1)).is_err(),
+            "should not receive events for a path that was unwatched"
+        );
+    }
+}
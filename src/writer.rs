@@ -0,0 +1,276 @@
+use crate::{make_meta_path, native::spawn_blocking, sandbox::Sandbox};
+use bevy::asset::io::{AssetWriter, AssetWriterError, Writer};
+use futures::io::AsyncWrite;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A writer that persists files directly to arbitrary paths.
+///
+/// This mirrors [`FileAssetReader`](crate::FileAssetReader): writes, renames, and removals
+/// all run as blocking `std::fs` calls on Bevy's `IoTaskPool` via [`spawn_blocking`], and every
+/// path is checked against the same [`Sandbox`] the reader uses.
+pub struct FileAssetWriter {
+    pub(crate) sandbox: Sandbox,
+}
+
+impl FileAssetWriter {
+    /// Opens a buffered, blocking-backed writer for the file at `path`, creating parent
+    /// directories as needed.
+    fn writer_for(path: PathBuf) -> Box<Writer> {
+        Box::new(BlockingFileWriter::new(path))
+    }
+
+    /// Checks `path` against the configured sandbox, mapping a rejection to the
+    /// [`AssetWriterError`] this trait's methods return.
+    fn check(&self, path: &Path) -> Result<PathBuf, AssetWriterError> {
+        self.sandbox.check(path).map_err(sandbox_denied)
+    }
+}
+
+/// Builds the `AssetWriterError` a sandboxed path rejection is reported as.
+fn sandbox_denied(path: PathBuf) -> AssetWriterError {
+    AssetWriterError::Io(io::Error::new(
+        io::ErrorKind::PermissionDenied,
+        format!("path is outside the configured sandbox: {}", path.display()),
+    ))
+}
+
+/// Builds the `AssetWriterError` a path with no meta-path-eligible extension is reported as.
+fn no_extension(path: &Path) -> AssetWriterError {
+    AssetWriterError::Io(io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("path has no extension: {}", path.display()),
+    ))
+}
+
+impl AssetWriter for FileAssetWriter {
+    async fn write<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        let path = self.check(path)?;
+        Ok(Self::writer_for(path))
+    }
+
+    async fn write_meta<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        let meta_path = make_meta_path(path).ok_or_else(|| no_extension(path))?;
+        let meta_path = self.check(&meta_path)?;
+        Ok(Self::writer_for(meta_path))
+    }
+
+    async fn remove<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        let path = self.check(path)?;
+        spawn_blocking(move || fs::remove_file(&path))
+            .await
+            .map_err(AssetWriterError::Io)
+    }
+
+    async fn remove_meta<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        let meta_path = make_meta_path(path).ok_or_else(|| no_extension(path))?;
+        let meta_path = self.check(&meta_path)?;
+        spawn_blocking(move || fs::remove_file(&meta_path))
+            .await
+            .map_err(AssetWriterError::Io)
+    }
+
+    async fn rename<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let old_path = self.check(old_path)?;
+        let new_path = self.check(new_path)?;
+        spawn_blocking(move || fs::rename(&old_path, &new_path))
+            .await
+            .map_err(AssetWriterError::Io)
+    }
+
+    async fn rename_meta<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let old_meta_path = make_meta_path(old_path).ok_or_else(|| no_extension(old_path))?;
+        let new_meta_path = make_meta_path(new_path).ok_or_else(|| no_extension(new_path))?;
+        let old_meta_path = self.check(&old_meta_path)?;
+        let new_meta_path = self.check(&new_meta_path)?;
+        spawn_blocking(move || fs::rename(&old_meta_path, &new_meta_path))
+            .await
+            .map_err(AssetWriterError::Io)
+    }
+
+    async fn remove_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        let path = self.check(path)?;
+        spawn_blocking(move || fs::remove_dir_all(&path))
+            .await
+            .map_err(AssetWriterError::Io)
+    }
+
+    async fn remove_empty_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        let path = self.check(path)?;
+        spawn_blocking(move || fs::remove_dir(&path))
+            .await
+            .map_err(AssetWriterError::Io)
+    }
+
+    async fn remove_assets_in_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let path = self.check(path)?;
+        spawn_blocking(move || {
+            for entry in fs::read_dir(&path)?.filter_map(|entry| entry.ok()) {
+                let entry_path = entry.path();
+                if entry_path.is_dir() {
+                    fs::remove_dir_all(entry_path)?;
+                } else {
+                    fs::remove_file(entry_path)?;
+                }
+            }
+            Ok(())
+        })
+        .await
+        .map_err(AssetWriterError::Io)
+    }
+}
+
+/// Buffers writes in memory and persists them to disk with a blocking `fs::write` call
+/// whenever the writer is flushed or closed.
+///
+/// `poll_flush` writes the buffered bytes through so that `AssetWriter`'s default
+/// `write_bytes`/`write_meta_bytes` helpers, which only call `write_all` followed by
+/// `flush()` and never `close()`, still end up with a durable file on disk.
+struct BlockingFileWriter {
+    path: PathBuf,
+    buffer: Vec<u8>,
+    pending: Option<Pin<Box<dyn std::future::Future<Output = io::Result<()>> + Send + Sync>>>,
+}
+
+impl BlockingFileWriter {
+    fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            buffer: Vec::new(),
+            pending: None,
+        }
+    }
+
+    /// Writes the buffered bytes to disk, reusing any in-flight write rather than starting
+    /// a second one. Safe to call repeatedly: each call persists the buffer's current
+    /// contents, so later writes that append more bytes are picked up by the next flush.
+    fn poll_persist(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        loop {
+            if let Some(pending) = self.pending.as_mut() {
+                let result = std::task::ready!(pending.as_mut().poll(cx));
+                self.pending = None;
+                return Poll::Ready(result);
+            }
+
+            let path = self.path.clone();
+            let bytes = self.buffer.clone();
+            self.pending = Some(Box::pin(spawn_blocking(move || {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, bytes)
+            })));
+        }
+    }
+}
+
+impl AsyncWrite for BlockingFileWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.buffer.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().poll_persist(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().poll_persist(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::tasks::{IoTaskPool, TaskPoolBuilder};
+    use futures::AsyncWriteExt;
+    use tempfile::tempdir;
+
+    fn init_io_task_pool() {
+        IoTaskPool::get_or_init(|| TaskPoolBuilder::default().build());
+    }
+
+    #[tokio::test]
+    async fn test_write_read_remove_round_trip() {
+        init_io_task_pool();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("asset.png");
+        let writer = FileAssetWriter {
+            sandbox: Sandbox::default(),
+        };
+
+        let mut file_writer = writer.write(&path).await.unwrap();
+        file_writer.write_all(b"hello").await.unwrap();
+        file_writer.close().await.unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"hello");
+
+        writer.remove(&path).await.unwrap();
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_rename() {
+        init_io_task_pool();
+        let dir = tempdir().unwrap();
+        let old_path = dir.path().join("old.png");
+        let new_path = dir.path().join("new.png");
+        let writer = FileAssetWriter {
+            sandbox: Sandbox::default(),
+        };
+
+        let mut file_writer = writer.write(&old_path).await.unwrap();
+        file_writer.write_all(b"hello").await.unwrap();
+        file_writer.close().await.unwrap();
+
+        writer.rename(&old_path, &new_path).await.unwrap();
+        assert!(!old_path.exists());
+        assert_eq!(fs::read(&new_path).unwrap(), b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_write_creates_missing_parent_directories() {
+        init_io_task_pool();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("a/b/c/asset.png");
+        let writer = FileAssetWriter {
+            sandbox: Sandbox::new(Some(vec![dir.path().to_path_buf()]), true),
+        };
+
+        let mut file_writer = writer.write(&path).await.unwrap();
+        file_writer.write_all(b"hello").await.unwrap();
+        file_writer.close().await.unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_write_bytes_persists_without_close() {
+        init_io_task_pool();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("asset.png");
+        let writer = FileAssetWriter {
+            sandbox: Sandbox::default(),
+        };
+
+        writer.write_bytes(&path, b"hello").await.unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"hello");
+    }
+}